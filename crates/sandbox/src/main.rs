@@ -4,43 +4,90 @@ use git2::{
     Blob, Commit, DiffLineType, DiffOptions, Oid, Pathspec, PathspecFlags, Repository, Sort, Time,
     Tree,
 };
-use itertools::Itertools;
+use moka::sync::Cache;
 use quote::ToTokens;
+use rayon::prelude::*;
+use serde::Serialize;
 use std::{
     borrow::Cow,
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     env,
     ops::{Range, RangeInclusive},
-    path::Path,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
 };
 use syn::spanned::Spanned;
 
-#[derive(Debug)]
-enum FieldChange<'a> {
-    Removed { name: &'a str },
-    Added { name: &'a str, ty: &'a str },
-    Renamed { from: &'a str, to: &'a str },
+/// Memoizes, per `(commit, path, extent)`, the member list of a tracked item
+/// — so repeated model lookups across a build (or across overlapping
+/// `TrackedModel` histories) reuse the parse instead of re-extracting and
+/// re-parsing the same blob. The extent is part of the key, not just the
+/// lookup input, because `track_models` runs every model's history through
+/// the same shared cache in parallel: two models can share a `(commit,
+/// path)` while tracking different items in that file, and keying on extent
+/// too keeps their entries from colliding.
+type StructCache = Cache<(Oid, PathBuf, Range<usize>), Arc<std::vec::Vec<(String, String)>>>;
+
+#[derive(Debug, Serialize)]
+enum FieldChange {
+    Removed { name: String },
+    Added { name: String, ty: String },
+    Renamed { from: String, to: String },
 }
 
-#[derive(Debug)]
-struct ChangeSet<'b, 'a> {
+#[derive(Debug, Serialize)]
+struct ChangeSet<'b> {
+    #[serde(serialize_with = "serialize_oid")]
     revision: Oid,
+    #[serde(serialize_with = "serialize_time")]
     time: Time,
-    data: Vec<'b, FieldChange<'a>>,
+    author: String,
+    email: String,
+    #[serde(serialize_with = "serialize_bump_vec")]
+    data: Vec<'b, FieldChange>,
+}
+
+fn serialize_oid<S>(oid: &Oid, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(&oid.to_string())
+}
+
+fn serialize_time<S>(time: &Time, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_i64(time.seconds())
+}
+
+/// `bumpalo::collections::Vec` has no `Serialize` impl of its own (bumpalo
+/// ships no `serde` feature for it), so every bump-allocated `Vec` field
+/// goes through this instead of a derive — serializing it as a plain
+/// sequence needs nothing more than `T: Serialize`.
+fn serialize_bump_vec<S, T>(vec: &Vec<'_, T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+    T: Serialize,
+{
+    serializer.collect_seq(vec.iter())
 }
 
 #[derive(Debug)]
 struct TrackedModel<'a> {
     name: Cow<'a, String>,
+    path: PathBuf,
     extent: RangeInclusive<usize>,
 }
 
-#[derive(Debug)]
-struct ChangeColletion<'b, 'a> {
-    change_sets: Vec<'b, ChangeSet<'b, 'a>>,
+#[derive(Debug, Serialize)]
+struct ChangeColletion<'b> {
+    #[serde(serialize_with = "serialize_bump_vec")]
+    change_sets: Vec<'b, ChangeSet<'b>>,
 }
 
-impl<'b, 'a> ChangeColletion<'b, 'a> {
+impl<'b> ChangeColletion<'b> {
     pub fn new(bump: &'b Bump) -> Self {
         ChangeColletion {
             change_sets: Vec::new_in(bump),
@@ -48,9 +95,183 @@ impl<'b, 'a> ChangeColletion<'b, 'a> {
     }
 }
 
-fn foobar() {
-    let b = Bump::new();
-    let cc = ChangeColletion::new(&b);
+/// Fields of a struct or union as `(name, type)` pairs, in declaration
+/// order. Tuple fields are keyed by their positional index.
+fn named_fields(fields: &syn::Fields) -> std::vec::Vec<(String, String)> {
+    match fields {
+        syn::Fields::Named(n) => n
+            .named
+            .iter()
+            .filter_map(|f| {
+                Some((
+                    f.ident.as_ref()?.to_string(),
+                    f.ty.to_token_stream().to_string(),
+                ))
+            })
+            .collect(),
+        syn::Fields::Unnamed(u) => u
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(i, f)| (i.to_string(), f.ty.to_token_stream().to_string()))
+            .collect(),
+        syn::Fields::Unit => std::vec::Vec::new(),
+    }
+}
+
+/// Member list of any tracked item, as `(name, type)` pairs: struct/union
+/// fields by name (or index, for tuple fields), and enum variants by name
+/// with their payload shape standing in for the "type" — so a variant
+/// whose payload changes diffs the same way a retyped field does, and
+/// [`diff_fields`]'s rename matching (same type, nearest index) works
+/// unchanged for variants too.
+fn item_members(item: &syn::Item) -> std::vec::Vec<(String, String)> {
+    match item {
+        syn::Item::Struct(s) => named_fields(&s.fields),
+        syn::Item::Union(u) => named_fields(&syn::Fields::Named(u.fields.clone())),
+        syn::Item::Enum(e) => e
+            .variants
+            .iter()
+            .map(|v| (v.ident.to_string(), v.fields.to_token_stream().to_string()))
+            .collect(),
+        _ => std::vec::Vec::new(),
+    }
+}
+
+/// Every top-level struct/enum/union name declared in `content`, in
+/// declaration order — used to build one `TrackedModel` per item so a
+/// whole file's schema can be audited in one batch instead of one
+/// hardcoded item at a time.
+fn item_names(content: &str) -> std::vec::Vec<String> {
+    let Some(file) = syn::parse_file(content).ok() else {
+        return std::vec::Vec::new();
+    };
+    file.items
+        .into_iter()
+        .filter_map(|item| match item {
+            syn::Item::Struct(s) => Some(s.ident.to_string()),
+            syn::Item::Enum(e) => Some(e.ident.to_string()),
+            syn::Item::Union(u) => Some(u.ident.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Fetch the cached member list for `commit`'s item at `path`'s `start..end`
+/// extent, extracting and parsing the blob on a cache miss. `start`/`end`
+/// are part of the cache key (not just the lookup input) so two models
+/// tracking different items in the same file at the same commit don't
+/// clobber each other's entry; on a warm cache they're consulted purely to
+/// build the key, skipping the blob extraction and `syn` parse entirely.
+fn cached_item_members(
+    cache: &StructCache,
+    repo: &Repository,
+    commit: &Commit,
+    path: &Path,
+    start: usize,
+    end: usize,
+) -> anyhow::Result<Arc<std::vec::Vec<(String, String)>>> {
+    let key = (commit.id(), path.to_path_buf(), start..end);
+    if let Some(hit) = cache.get(&key) {
+        return Ok(hit);
+    }
+
+    let blob = extract_blob(repo, commit, path)?;
+    let content = std::str::from_utf8(blob.content())?;
+    let snippet = &content[start..end];
+    let item = syn::parse_str::<syn::Item>(snippet).with_context(|| snippet.to_string())?;
+
+    let value = Arc::new(item_members(&item));
+    cache.insert(key, value.clone());
+    Ok(value)
+}
+
+/// Classic Levenshtein edit distance, used to break ties between equally
+/// plausible rename candidates.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: std::vec::Vec<char> = a.chars().collect();
+    let b: std::vec::Vec<char> = b.chars().collect();
+
+    let mut dp: std::vec::Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut prev = dp[0];
+        dp[0] = i;
+        for j in 1..=b.len() {
+            let tmp = dp[j];
+            dp[j] = if a[i - 1] == b[j - 1] {
+                prev
+            } else {
+                1 + prev.min(dp[j]).min(dp[j - 1])
+            };
+            prev = tmp;
+        }
+    }
+    dp[b.len()]
+}
+
+/// Diff two field lists, pairing up same-typed removals/additions as renames.
+///
+/// A removal candidate is only matched against an addition candidate when
+/// their type strings agree, which keeps a same-name retype from being
+/// mistaken for a rename: it shows up as a plain `Removed` + `Added` pair
+/// instead. Among same-typed candidates, the pair with the smallest field
+/// index distance wins; ties are broken by smallest name Levenshtein
+/// distance.
+fn diff_fields<'b>(
+    bump: &'b Bump,
+    old_fields: &[(String, String)],
+    new_fields: &[(String, String)],
+) -> Vec<'b, FieldChange> {
+    let removed: std::vec::Vec<(usize, &(String, String))> = old_fields
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| !new_fields.contains(f))
+        .collect();
+    let added: std::vec::Vec<(usize, &(String, String))> = new_fields
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| !old_fields.contains(f))
+        .collect();
+
+    let mut consumed_added = HashSet::new();
+    let mut renamed_from = HashSet::new();
+
+    let mut changes = Vec::new_in(bump);
+
+    for (old_idx, (old_name, old_ty)) in &removed {
+        let best = added
+            .iter()
+            .enumerate()
+            .filter(|(pos, (_, (_, new_ty)))| !consumed_added.contains(pos) && new_ty == old_ty)
+            .min_by_key(|(_, (new_idx, (new_name, _)))| {
+                (old_idx.abs_diff(*new_idx), levenshtein(old_name, new_name))
+            });
+
+        if let Some((pos, (_, (new_name, _)))) = best {
+            consumed_added.insert(pos);
+            renamed_from.insert(*old_idx);
+            changes.push(FieldChange::Renamed {
+                from: old_name.clone(),
+                to: new_name.clone(),
+            });
+        }
+    }
+
+    for (old_idx, (name, _)) in &removed {
+        if !renamed_from.contains(old_idx) {
+            changes.push(FieldChange::Removed { name: name.clone() });
+        }
+    }
+    for (pos, (_, (name, ty))) in added.iter().enumerate() {
+        if !consumed_added.contains(&pos) {
+            changes.push(FieldChange::Added {
+                name: name.clone(),
+                ty: ty.clone(),
+            });
+        }
+    }
+
+    changes
 }
 
 fn match_with_parent(
@@ -63,12 +284,14 @@ fn match_with_parent(
     Ok(diff.deltas().len() > 0)
 }
 
-fn tracking<'a>(
+fn tracking<'a, 'b>(
+    cache: &StructCache,
+    bump: &'b Bump,
     repo: &'a Repository,
     path: &'a Path,
-    mut start: usize,
-    mut end: usize,
-) -> anyhow::Result<()> {
+    start: usize,
+    end: usize,
+) -> anyhow::Result<ChangeColletion<'b>> {
     let ps = Pathspec::new(&[&path])?;
     let mut diff_opts = DiffOptions::new();
     diff_opts.pathspec(&path);
@@ -100,11 +323,19 @@ fn tracking<'a>(
         }
     });
 
-    let mut changes = HashMap::new();
+    let mut collection = ChangeColletion::new(bump);
 
     let prev = commits.next().ok_or(anyhow!("rel sad"))?;
     let mut prev_blob = extract_blob(repo, &prev, path)?;
 
+    let prev_cached = cached_item_members(cache, repo, &prev, path, start, end)?;
+    let mut prev_members = (*prev_cached).clone();
+    let mut prev_commit = prev.clone();
+
+    let prev_content = std::str::from_utf8(prev_blob.content())?;
+    let mut lines =
+        line_at_offset(prev_content, start)..=line_at_offset(prev_content, end.saturating_sub(1));
+
     let mut diff_opts = DiffOptions::new();
     diff_opts.pathspec(path);
     diff_opts.context_lines(0);
@@ -112,10 +343,7 @@ fn tracking<'a>(
     for curr in commits {
         let curr_blob = extract_blob(repo, &curr, path)?;
 
-        let mut start_move = start;
-        let mut end_move = end;
-
-        let mut foo = vec![];
+        let mut hunks = std::vec::Vec::new();
         repo.diff_blobs(
             Some(&prev_blob),
             None,
@@ -126,45 +354,29 @@ fn tracking<'a>(
             None,
             None,
             Some(&mut |_d, _h, l| {
-                let content_offset = l.content_offset() as usize;
-                let len = l.content().len();
-                foo.push((l.origin_value(), l.old_lineno().or(l.new_lineno())));
-                match l.origin_value() {
-                    DiffLineType::Addition => {
-                        if start > content_offset {
-                            start_move += len;
-                        }
-                        if end > content_offset {
-                            end_move += len;
-                        }
-                    }
-                    DiffLineType::Deletion => {
-                        if start > content_offset {
-                            start_move -= len;
-                        }
-                        if end > content_offset {
-                            end_move -= len;
-                        }
-                    }
-                    _ => {}
-                };
-                content_offset <= end
+                hunks.push(LineChange {
+                    op: l.origin_value(),
+                    old_line: l.old_lineno(),
+                    new_line: l.new_lineno(),
+                    content: String::from_utf8_lossy(l.content()).into_owned(),
+                });
+                true
             }),
         )?;
 
-        println!("changes: {:#?}", foo);
+        println!("changes: {:#?}", hunks);
 
-        start = start_move;
-        end = end_move;
+        lines = remap_line_range(&lines, &hunks);
 
-        if end <= start {
+        if lines.end() <= lines.start() {
             break;
         }
 
         let curr_content = std::str::from_utf8(curr_blob.content())?;
+        let byte_range = byte_range_of_lines(curr_content, &lines);
 
-        let snippet = &curr_content[start as usize..end as usize];
-        let s = syn::parse_str::<syn::ItemStruct>(snippet).with_context(|| snippet.to_string())?;
+        let curr_cached =
+            cached_item_members(cache, repo, &curr, path, byte_range.start, byte_range.end)?;
 
         /* match &s.fields {
             syn::Fields::Named(n) => {
@@ -189,24 +401,34 @@ fn tracking<'a>(
             }
         } */
 
-        changes.insert(
-            s.fields.to_token_stream().to_string(),
-            (curr.id(), curr.time()),
-        );
+        // The walk runs newest-first, so `curr` is the older side of each
+        // pair: diff it forward to `prev_members` and record the change at
+        // `prev_commit`, the commit that made it.
+        let curr_members = (*curr_cached).clone();
+        let data = diff_fields(bump, &curr_members, &prev_members);
+
+        if !data.is_empty() {
+            let author = prev_commit.author();
+            collection.change_sets.push(ChangeSet {
+                revision: prev_commit.id(),
+                time: prev_commit.time(),
+                author: author.name().unwrap_or("unknown").to_string(),
+                email: author.email().unwrap_or("unknown").to_string(),
+                data,
+            });
+        }
 
+        prev_members = curr_members;
+        prev_commit = curr;
         prev_blob = curr_blob;
     }
 
-    let changes = changes
-        .into_iter()
-        .sorted_unstable_by_key(|(_, (_, time))| time.seconds())
-        .map(|(k, _)| k)
-        .collect_vec();
+    collection.change_sets.sort_by_key(|cs| cs.time.seconds());
 
-    println!("{:#?}", changes);
+    println!("{:#?}", collection);
     println!("processed {count} commits");
 
-    Ok(())
+    Ok(collection)
 }
 
 fn extract_blob<'a, 'b, 'c>(
@@ -221,37 +443,329 @@ fn extract_blob<'a, 'b, 'c>(
     Ok(blob)
 }
 
+/// Whether the struct named `struct_name` in `path` at `commit` declares a
+/// named field `field_name`. The monotonic predicate fed to [`bisect_boundary`]
+/// when hunting for the commit that introduced or removed a field.
+fn struct_has_field(
+    repo: &Repository,
+    path: &Path,
+    struct_name: &str,
+    field_name: &str,
+    commit: &Commit,
+) -> anyhow::Result<bool> {
+    let blob = extract_blob(repo, commit, path)?;
+    let content = std::str::from_utf8(blob.content())?;
+    let Some((start, end)) = find_start_end(content, &format!("struct {struct_name}")) else {
+        return Ok(false);
+    };
+    let item = syn::parse_str::<syn::ItemStruct>(&content[start..end])?;
+    Ok(named_fields(&item.fields)
+        .iter()
+        .any(|(name, _)| name == field_name))
+}
+
+/// Binary-search a time-ordered commit slice for the single boundary where
+/// `predicate` flips value, assuming it is monotonic over the range (e.g.
+/// "struct contains field `x`" holds false then true, or vice versa for a
+/// removal). Runs in O(log n) predicate evaluations instead of a linear walk.
+///
+/// Returns `None` when the endpoints agree, i.e. there's no flip in range —
+/// either the predicate is constant throughout, or it isn't monotonic here
+/// and [`find_boundaries`] should be used instead.
+fn bisect_boundary<'r>(
+    commits: &[Commit<'r>],
+    predicate: &dyn Fn(&Commit<'r>) -> anyhow::Result<bool>,
+) -> anyhow::Result<Option<Commit<'r>>> {
+    if commits.is_empty() {
+        return Ok(None);
+    }
+
+    let target = predicate(&commits[commits.len() - 1])?;
+    if predicate(&commits[0])? == target {
+        return Ok(None);
+    }
+
+    let mut lo = 0usize;
+    let mut hi = commits.len() - 1;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if predicate(&commits[mid])? == target {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+
+    Ok(Some(commits[lo].clone()))
+}
+
+/// Like [`bisect_boundary`], but verifies the monotonic assumption at the
+/// endpoints of every range it looks at. When the predicate agrees with
+/// itself at both ends (the field was added and later removed again, or
+/// re-added after removal), a single flip can't explain the whole range, so
+/// each half is bisected independently — overlapping at the midpoint so a
+/// flip sitting exactly on the split isn't missed — and every boundary found
+/// is reported instead of just the first.
+fn find_boundaries<'r>(
+    commits: &[Commit<'r>],
+    predicate: &dyn Fn(&Commit<'r>) -> anyhow::Result<bool>,
+) -> anyhow::Result<std::vec::Vec<Commit<'r>>> {
+    if commits.len() < 2 {
+        return Ok(std::vec::Vec::new());
+    }
+
+    let first = predicate(&commits[0])?;
+    let last = predicate(&commits[commits.len() - 1])?;
+
+    if first != last {
+        return Ok(bisect_boundary(commits, predicate)?.into_iter().collect());
+    }
+
+    // Two adjacent commits that agree have no flip between them.
+    if commits.len() == 2 {
+        return Ok(std::vec::Vec::new());
+    }
+
+    let mid = commits.len() / 2;
+    let mut boundaries = find_boundaries(&commits[..=mid], predicate)?;
+    boundaries.extend(find_boundaries(&commits[mid..], predicate)?);
+    Ok(boundaries)
+}
+
+/// Find the commit(s) where `struct_name` in `path` gained or lost
+/// `field_name`, walking `repo`'s history oldest-first and bisecting it with
+/// [`find_boundaries`] rather than checking every commit in order.
+fn find_field_introduction(
+    repo: &Repository,
+    path: &Path,
+    struct_name: &str,
+    field_name: &str,
+) -> anyhow::Result<std::vec::Vec<Commit>> {
+    let mut revwalk = repo.revwalk()?;
+    revwalk.set_sorting(Sort::TIME | Sort::REVERSE)?;
+    revwalk.push_head()?;
+
+    let commits = revwalk
+        .map(|oid| Ok(repo.find_commit(oid?)?))
+        .collect::<anyhow::Result<std::vec::Vec<_>>>()?;
+
+    find_boundaries(&commits, &|commit| {
+        struct_has_field(repo, path, struct_name, field_name, commit)
+    })
+}
+
+/// One line of a zero-context `diff_blobs` hunk, carrying both sides' line
+/// numbers so a tracked extent can be remapped from hunk headers instead of
+/// accumulating byte lengths.
+#[derive(Debug)]
+struct LineChange {
+    op: DiffLineType,
+    old_line: Option<u32>,
+    new_line: Option<u32>,
+    content: String,
+}
+
+/// 1-indexed line number containing byte `offset` in `content`.
+fn line_at_offset(content: &str, offset: usize) -> usize {
+    content[..offset].matches('\n').count() + 1
+}
+
+/// Byte range spanning the 1-indexed inclusive line range `lines` in `content`,
+/// up to but not including the newline that ends the last line.
+fn byte_range_of_lines(content: &str, lines: &RangeInclusive<usize>) -> Range<usize> {
+    let mut line_starts = std::iter::once(0).chain(content.match_indices('\n').map(|(i, _)| i + 1));
+    let start = line_starts
+        .clone()
+        .nth(lines.start() - 1)
+        .unwrap_or(content.len());
+    let end = line_starts
+        .nth(*lines.end())
+        .map(|next_line_start| next_line_start - 1)
+        .unwrap_or(content.len());
+    start..end
+}
+
+/// Remap a tracked line range across the lines of a zero-context diff:
+/// additions before the range shift it down, deletions before it shift it
+/// up, and additions/deletions inside it expand/contract it.
+///
+/// Deletions are classified by their old-file line number against the
+/// range as it stood *before the diff* — not the boundary as it's being
+/// rewritten by earlier lines — so two shifts above the range (e.g. two
+/// unrelated deletions) both register instead of the second one missing
+/// because `start` already moved past it. Additions are classified by their
+/// new-file line number against the range as shifted by the lines before
+/// them, which is where the range sits in the new file.
+fn remap_line_range(
+    lines: &RangeInclusive<usize>,
+    hunks: &[LineChange],
+) -> RangeInclusive<usize> {
+    let orig_start = *lines.start();
+    let orig_end = *lines.end();
+
+    let mut start = orig_start;
+    let mut end = orig_end;
+
+    for change in hunks {
+        match change.op {
+            DiffLineType::Addition => {
+                let Some(new_line) = change.new_line.map(|n| n as usize) else {
+                    continue;
+                };
+                if new_line <= start {
+                    start += 1;
+                    end += 1;
+                } else if new_line <= end {
+                    end += 1;
+                }
+            }
+            DiffLineType::Deletion => {
+                let Some(old_line) = change.old_line.map(|n| n as usize) else {
+                    continue;
+                };
+                if old_line < orig_start {
+                    start = start.saturating_sub(1);
+                    end = end.saturating_sub(1);
+                } else if old_line <= orig_end {
+                    end = end.saturating_sub(1);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    start..=end
+}
+
 fn find_start_end(content: &str, needle: &str) -> Option<(usize, usize)> {
     let start = content.find(needle)?;
-    let end = start + content[start..].find('}')? + 1;
-    //adjust end to nearest preceding newline
-    let start = content[..start + 1].rfind('\n').unwrap_or(start);
+    let end = find_item_end(content, start)?;
+    // Back `start` up to the beginning of its own line (one past the
+    // preceding newline, not the newline itself) so the extent's first line
+    // is the item's line rather than the one before it.
+    let start = content[..start]
+        .rfind('\n')
+        .map(|i| i + 1)
+        .unwrap_or(0);
     Some((start, end))
 }
 
+/// Scan forward from `start`, balancing `{}`/`()` to find where the item
+/// declared there actually ends, rather than stopping at the first `}` —
+/// so nested generics and inner blocks don't truncate the extent. Also
+/// handles the brace-less forms (unit and tuple structs), which terminate
+/// at the first top-level `;` instead.
+fn find_item_end(content: &str, start: usize) -> Option<usize> {
+    let mut brace_depth = 0i32;
+    let mut paren_depth = 0i32;
+    let mut opened = false;
+
+    for (i, b) in content.bytes().enumerate().skip(start) {
+        match b {
+            b'{' => {
+                brace_depth += 1;
+                opened = true;
+            }
+            b'}' => {
+                brace_depth -= 1;
+                if opened && brace_depth == 0 && paren_depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            b'(' => {
+                paren_depth += 1;
+                opened = true;
+            }
+            b')' => paren_depth -= 1,
+            b';' if brace_depth == 0 && paren_depth == 0 => return Some(i + 1),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Locate an item (`struct`, `enum`, or `union`) named `name` in `content`,
+/// trying each possible keyword and taking the earliest match.
+fn find_item(content: &str, name: &str) -> Option<(usize, usize)> {
+    ["struct", "enum", "union"]
+        .into_iter()
+        .filter_map(|kw| find_start_end(content, &format!("{kw} {name}")))
+        .min_by_key(|(start, _)| *start)
+}
+
 impl<'a> TrackedModel<'a> {
-    pub fn from_content(name: Cow<'a, String>, content: &str) -> Option<Self> {
-        let start = content.find(name.as_str())?;
-        let end = start + content[start..].find('}')? + 1;
-        //adjust end to nearest preceding newline
-        let start = content[..start + 1].rfind('\n').unwrap_or(start);
+    pub fn from_content(name: Cow<'a, String>, path: PathBuf, content: &str) -> Option<Self> {
+        let (start, end) = find_item(content, name.as_str())?;
 
         Some(Self {
             name,
+            path,
             extent: start..=end,
         })
     }
 
-    pub fn gather_revisions(&self, repo: &Repository) -> anyhow::Result<()> {
-        Ok(())
+    pub fn gather_revisions<'b>(
+        &self,
+        cache: &StructCache,
+        bump: &'b Bump,
+        repo: &Repository,
+    ) -> anyhow::Result<ChangeColletion<'b>> {
+        tracking(
+            cache,
+            bump,
+            repo,
+            &self.path,
+            *self.extent.start(),
+            *self.extent.end(),
+        )
     }
 }
 
+/// Walk every model's history in parallel with rayon. `Repository` isn't
+/// `Sync`, so each task opens its own handle on `repo_path` rather than
+/// sharing one across threads; likewise a `Bump` arena is single-threaded,
+/// so each task gets its own and flattens its `ChangeColletion` to owned
+/// JSON before returning, instead of trying to unify every run's arena
+/// lifetime into one result type.
+fn track_models(
+    cache: &StructCache,
+    repo_path: &Path,
+    models: &[TrackedModel],
+) -> anyhow::Result<HashMap<String, serde_json::Value>> {
+    models
+        .par_iter()
+        .map(|model| -> anyhow::Result<(String, serde_json::Value)> {
+            let repo = Repository::open(repo_path)?;
+            let bump = Bump::new();
+            let collection = model.gather_revisions(cache, &bump, &repo)?;
+            Ok((model.name.to_string(), serde_json::to_value(&collection)?))
+        })
+        .collect::<anyhow::Result<std::vec::Vec<_>>>()
+        .map(|entries| entries.into_iter().collect())
+}
+
 fn main() -> anyhow::Result<()> {
     let curr_dir = env::current_dir()?;
     let repo = Repository::discover(&curr_dir.join("repos/basic"))?;
     let file_path = Path::new("basic.rs");
 
+    let args: std::vec::Vec<String> = env::args().collect();
+    if let Some(pos) = args.iter().position(|a| a == "--find-field") {
+        let struct_name = args
+            .get(pos + 1)
+            .ok_or(anyhow!("--find-field requires <struct> <field>"))?;
+        let field_name = args
+            .get(pos + 2)
+            .ok_or(anyhow!("--find-field requires <struct> <field>"))?;
+
+        let boundaries = find_field_introduction(&repo, file_path, struct_name, field_name)?;
+        for commit in &boundaries {
+            println!("{} {}", commit.id(), commit.summary().unwrap_or(""));
+        }
+        return Ok(());
+    }
+
     let mut diff_opts = DiffOptions::new();
     diff_opts.pathspec(file_path);
     diff_opts.context_lines(0);
@@ -266,12 +780,33 @@ fn main() -> anyhow::Result<()> {
     let head = head.peel_to_commit()?;
     let blob = extract_blob(&repo, &head, file_path)?;
     let content = std::str::from_utf8(blob.content())?;
-    let (start, end) =
-        find_start_end(&content, "struct Foobar").ok_or(anyhow!("Failed to find struct"))?;
+
+    let models: std::vec::Vec<TrackedModel> = item_names(content)
+        .into_iter()
+        .filter_map(|name| {
+            TrackedModel::from_content(Cow::Owned(name), file_path.to_path_buf(), content)
+        })
+        .collect();
+
+    if models.is_empty() {
+        return Err(anyhow!("Failed to find any struct/enum/union in {file_path:?}"));
+    }
 
     let start_time = std::time::Instant::now();
 
-    tracking(&repo, file_path, start, end)?;
+    let cache: StructCache = Cache::builder()
+        .max_capacity(10_000)
+        .time_to_live(Duration::from_secs(300))
+        .build();
+    let results = track_models(&cache, &curr_dir.join("repos/basic"), &models)?;
+
+    let pretty = env::args().any(|a| a == "--pretty");
+    let json = if pretty {
+        serde_json::to_string_pretty(&results)?
+    } else {
+        serde_json::to_string(&results)?
+    };
+    println!("{json}");
 
     let end = std::time::Instant::now();
 
@@ -279,3 +814,110 @@ fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_start_end_does_not_swallow_the_preceding_line() {
+        let content = "use foo::Bar;\nstruct Foo {\n    x: i32,\n}\n";
+        let (start, end) = find_start_end(content, "struct Foo").unwrap();
+
+        assert_eq!(&content[start..end], "struct Foo {\n    x: i32,\n}");
+        assert_eq!(line_at_offset(content, start), 2);
+    }
+
+    #[test]
+    fn line_and_byte_ranges_round_trip() {
+        let content = "use foo::Bar;\nstruct Foo {\n    x: i32,\n}\n";
+        let (start, end) = find_start_end(content, "struct Foo").unwrap();
+
+        let lines = line_at_offset(content, start)..=line_at_offset(content, end - 1);
+        let byte_range = byte_range_of_lines(content, &lines);
+
+        assert_eq!(&content[byte_range], &content[start..end]);
+    }
+
+    #[test]
+    fn remap_line_range_absorbs_every_upstream_deletion() {
+        // Struct originally spans old-file lines [10, 20]; two unrelated
+        // deletions above it (old lines 3 and 9) should shift both bounds
+        // down by 2, not just 1.
+        let hunks = [
+            LineChange {
+                op: DiffLineType::Deletion,
+                old_line: Some(3),
+                new_line: None,
+                content: String::new(),
+            },
+            LineChange {
+                op: DiffLineType::Deletion,
+                old_line: Some(9),
+                new_line: None,
+                content: String::new(),
+            },
+        ];
+
+        assert_eq!(remap_line_range(&(10..=20), &hunks), 8..=18);
+    }
+
+    #[test]
+    fn remap_line_range_expands_for_addition_inside_range() {
+        let hunks = [LineChange {
+            op: DiffLineType::Addition,
+            old_line: None,
+            new_line: Some(15),
+            content: String::new(),
+        }];
+
+        assert_eq!(remap_line_range(&(10..=20), &hunks), 10..=21);
+    }
+
+    #[test]
+    fn diff_fields_pairs_same_typed_rename_over_plain_add_remove() {
+        let bump = Bump::new();
+        let old_fields = [
+            ("a".to_string(), "i32".to_string()),
+            ("b".to_string(), "String".to_string()),
+        ];
+        let new_fields = [
+            ("a2".to_string(), "i32".to_string()),
+            ("b".to_string(), "String".to_string()),
+        ];
+
+        let changes = diff_fields(&bump, &old_fields, &new_fields);
+
+        assert_eq!(changes.len(), 1);
+        match &changes[0] {
+            FieldChange::Renamed { from, to } => {
+                assert_eq!(from, "a");
+                assert_eq!(to, "a2");
+            }
+            other => panic!("expected Renamed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn diff_fields_treats_same_name_retype_as_remove_plus_add() {
+        let bump = Bump::new();
+        let old_fields = [("a".to_string(), "i32".to_string())];
+        let new_fields = [("a".to_string(), "String".to_string())];
+
+        let changes = diff_fields(&bump, &old_fields, &new_fields);
+
+        assert_eq!(changes.len(), 2);
+        assert!(changes
+            .iter()
+            .any(|c| matches!(c, FieldChange::Removed { name } if name == "a")));
+        assert!(changes
+            .iter()
+            .any(|c| matches!(c, FieldChange::Added { name, ty } if name == "a" && ty == "String")));
+    }
+
+    #[test]
+    fn levenshtein_distances() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("same", "same"), 0);
+    }
+}